@@ -0,0 +1,50 @@
+use bytes::BytesMut;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use postgres_array::primitive::PrimitiveArray;
+use postgres_array::Array;
+use postgres_types::{FromSql, ToSql, Type};
+
+const LEN: i32 = 1_000_000;
+
+fn decode(c: &mut Criterion) {
+    let array = Array::from_vec((0..LEN).collect::<Vec<i32>>(), 1);
+    let mut raw = BytesMut::new();
+    array.to_sql(&Type::INT4_ARRAY, &mut raw).unwrap();
+
+    let mut group = c.benchmark_group("decode_int4");
+    group.bench_function("generic", |b| {
+        b.iter(|| Array::<i32>::from_sql(&Type::INT4_ARRAY, black_box(&raw)).unwrap())
+    });
+    group.bench_function("primitive", |b| {
+        b.iter(|| PrimitiveArray::<i32>::from_sql(&Type::INT4_ARRAY, black_box(&raw)).unwrap())
+    });
+    group.finish();
+}
+
+fn encode(c: &mut Criterion) {
+    let array = Array::from_vec((0..LEN).collect::<Vec<i32>>(), 1);
+    let primitive = PrimitiveArray(array.clone());
+    let mut buf = BytesMut::new();
+
+    let mut group = c.benchmark_group("encode_int4");
+    group.bench_function("generic", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&array)
+                .to_sql(&Type::INT4_ARRAY, &mut buf)
+                .unwrap();
+        })
+    });
+    group.bench_function("primitive", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&primitive)
+                .to_sql(&Type::INT4_ARRAY, &mut buf)
+                .unwrap();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, decode, encode);
+criterion_main!(benches);
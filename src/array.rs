@@ -1,60 +1,38 @@
+use std::cmp;
 use std::fmt;
-use std::ops::{Index, IndexMut};
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
 use std::slice;
 use std::vec;
 
-use crate::Dimension;
+use crate::text;
+use crate::view::{Lanes, LanesMut, OuterIter, OuterIterMut};
+use crate::{ArrayError, ArrayView, ArrayViewMut, Dimension, MapError};
+
+/// The maximum number of dimensions of a Postgres array.
+pub const MAX_DIMENSIONS: usize = 6;
+
+/// The maximum number of elements of a Postgres array.
+pub const MAX_ELEMENTS: usize = 0x3fff_ffff / 8;
 
 /// A multi-dimensional array.
+///
+/// An array has at most `MAX_DIMENSIONS` dimensions and `MAX_ELEMENTS`
+/// elements, and the upper bound of each dimension fits in an `i32`. The
+/// operations which build arrays return an error, or panic, if these limits
+/// would be exceeded.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Array<T> {
     dims: Vec<Dimension>,
     data: Vec<T>,
 }
 
+/// Formats the array as a Postgres array literal.
+///
+/// Elements are quoted and escaped as needed. See `text::write` for details.
 impl<T: fmt::Display> fmt::Display for Array<T> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.dims.iter().any(|dim| dim.lower_bound != 1) {
-            for dim in &self.dims {
-                write!(
-                    fmt,
-                    "[{}:{}]",
-                    dim.lower_bound,
-                    dim.lower_bound + dim.len - 1
-                )?;
-            }
-            write!(fmt, "=")?;
-        }
-        fmt_helper(0, &self.dims, &mut self.data.iter(), fmt)
-    }
-}
-
-fn fmt_helper<'a, T, I>(
-    depth: usize,
-    dims: &[Dimension],
-    data: &mut I,
-    fmt: &mut fmt::Formatter<'_>,
-) -> fmt::Result
-where
-    I: Iterator<Item = &'a T>,
-    T: 'a + fmt::Display,
-{
-    if dims.len() == 0 {
-        return write!(fmt, "{{}}");
-    }
-
-    if depth == dims.len() {
-        return write!(fmt, "{}", data.next().unwrap());
+        text::write(fmt, self, ',', Some)
     }
-
-    write!(fmt, "{{")?;
-    for i in 0..dims[depth].len {
-        if i != 0 {
-            write!(fmt, ",")?;
-        }
-        fmt_helper(depth + 1, dims, data, fmt)?;
-    }
-    write!(fmt, "}}")
 }
 
 impl<T> Array<T> {
@@ -66,35 +44,106 @@ impl<T> Array<T> {
     /// # Panics
     ///
     /// Panics if the number of elements provided does not match the number of
-    /// elements specified by the dimensions.
+    /// elements specified by the dimensions, or if the dimensions exceed the
+    /// Postgres array limits.
     pub fn from_parts(data: Vec<T>, dimensions: Vec<Dimension>) -> Array<T> {
-        assert!(
-            (data.is_empty() && dimensions.is_empty())
-                || data.len() as i32 == dimensions.iter().fold(1, |acc, i| acc * i.len),
-            "size mismatch"
-        );
-        Array {
+        Array::try_from_parts(data, dimensions).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `from_parts`, but returns an error instead of panicking if the
+    /// number of elements provided does not match the number of elements
+    /// specified by the dimensions, or if the dimensions exceed the Postgres
+    /// array limits.
+    pub fn try_from_parts(
+        data: Vec<T>,
+        dimensions: Vec<Dimension>,
+    ) -> Result<Array<T>, ArrayError> {
+        let dimensions = check_size(dimensions, data.len())?;
+        Ok(Array {
             dims: dimensions,
             data,
-        }
+        })
     }
 
     /// Creates a new one-dimensional array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array would exceed the Postgres array limits.
     pub fn from_vec(data: Vec<T>, lower_bound: i32) -> Array<T> {
-        Array {
-            dims: vec![Dimension {
-                len: data.len() as i32,
-                lower_bound,
-            }],
+        Array::try_from_vec(data, lower_bound).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `from_vec`, but returns an error instead of panicking if the
+    /// array would exceed the Postgres array limits.
+    pub fn try_from_vec(data: Vec<T>, lower_bound: i32) -> Result<Array<T>, ArrayError> {
+        let dim = vec_dimension(data.len(), lower_bound)?;
+        Ok(Array {
+            dims: vec![dim],
             data,
+        })
+    }
+
+    /// Creates a new empty array with no dimensions which can hold at least
+    /// `capacity` elements without reallocating.
+    ///
+    /// This is mainly useful with `decode_into`, which refills an existing
+    /// array in place.
+    pub fn with_capacity(capacity: usize) -> Array<T> {
+        Array {
+            dims: vec![],
+            data: Vec::with_capacity(capacity),
         }
     }
 
+    /// Returns the number of elements the array can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more elements than the
+    /// array currently contains.
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
     /// Wraps this array in a new dimension of size 1.
     ///
     /// For example, the one dimensional array `[1, 2]` would turn into the
     /// two-dimensional array `[[1, 2]]`.
+    ///
+    /// An array with no dimensions is given a single dimension of size 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this array already has `MAX_DIMENSIONS` dimensions.
     pub fn wrap(&mut self, lower_bound: i32) {
+        if self.dims.is_empty() {
+            self.dims.push(Dimension {
+                len: 1,
+                lower_bound,
+            });
+            return;
+        }
+        self.try_wrap(lower_bound)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `wrap`, but returns an error instead of panicking if this array
+    /// already has `MAX_DIMENSIONS` dimensions, and also returns an error if
+    /// this array has no dimensions.
+    ///
+    /// The array is left unchanged if an error is returned.
+    pub fn try_wrap(&mut self, lower_bound: i32) -> Result<(), ArrayError> {
+        if self.dims.is_empty() {
+            return Err(ArrayError::NoDimensions);
+        }
+        if self.dims.len() >= MAX_DIMENSIONS {
+            return Err(ArrayError::TooManyDimensions {
+                len: self.dims.len() + 1,
+            });
+        }
         self.dims.insert(
             0,
             Dimension {
@@ -102,6 +151,7 @@ impl<T> Array<T> {
                 lower_bound,
             },
         );
+        Ok(())
     }
 
     /// Consumes another array, appending it to the top level dimension of this
@@ -116,17 +166,181 @@ impl<T> Array<T> {
     ///
     /// # Panics
     ///
-    /// Panics if the dimensions of the two arrays do not match.
+    /// Panics if the dimensions of the two arrays do not match, or if the
+    /// result would exceed the Postgres array limits.
     pub fn push(&mut self, other: Array<T>) {
-        assert!(
-            self.dims.len() - 1 == other.dims.len(),
-            "cannot append differently shaped arrays"
-        );
-        for (dim1, dim2) in self.dims.iter().skip(1).zip(other.dims.iter()) {
-            assert!(dim1 == dim2, "cannot append differently shaped arrays");
+        self.try_push(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `push`, but returns an error instead of panicking if the
+    /// dimensions of the two arrays do not match, or if the result would
+    /// exceed the Postgres array limits.
+    ///
+    /// The array is left unchanged if an error is returned.
+    pub fn try_push(&mut self, other: Array<T>) -> Result<(), ArrayError> {
+        let expected = self.dims.get(1..).unwrap_or(&[]);
+        if self.dims.is_empty() || expected != &other.dims[..] {
+            return Err(ArrayError::ShapeMismatch {
+                expected: expected.to_vec(),
+                actual: other.dims,
+            });
         }
-        self.dims[0].len += 1;
+        let mut dims = self.dims.clone();
+        dims[0].len += 1;
+        check_dims(&dims)?;
+        self.dims = dims;
         self.data.extend(other.data);
+        Ok(())
+    }
+
+    /// Concatenates arrays along the specified axis.
+    ///
+    /// Along the first axis, this follows the semantics of the Postgres
+    /// `array_cat` function and `||` operator, applied from left to right:
+    ///
+    /// * Empty arrays are ignored.
+    /// * Two N-dimensional arrays are joined along their first dimension.
+    ///   Their remaining dimensions must match, and the result keeps the lower
+    ///   bound of the left array's first dimension.
+    /// * An (N-1)-dimensional array is appended to an N-dimensional array as
+    ///   one more entry of its first dimension, or prepended to an
+    ///   N-dimensional array to its right, in which case the result keeps the
+    ///   lower bound of the right array's first dimension. The dimensions of
+    ///   the smaller array must match the remaining dimensions of the larger
+    ///   one.
+    ///
+    /// Along any other axis, all non-empty arrays must have the same number of
+    /// dimensions, and all dimensions other than `axis` must match. The
+    /// result keeps the lower bounds of the first non-empty array.
+    ///
+    /// Dimensions match if both their lengths and lower bounds are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// // The equivalent of `ARRAY[[1,2]] || ARRAY[3,4] || ARRAY[[5,6]]`
+    /// let mut a = Array::from_vec(vec![1i32, 2], 1);
+    /// a.wrap(1);
+    /// let b = Array::from_vec(vec![3, 4], 1);
+    /// let mut c = Array::from_vec(vec![5, 6], 1);
+    /// c.wrap(1);
+    ///
+    /// let array = Array::concat(0, vec![a, b, c]).unwrap();
+    /// assert_eq!("{{1,2},{3,4},{5,6}}", array.to_string());
+    ///
+    /// let array = Array::concat(1, vec![array.clone(), array]).unwrap();
+    /// assert_eq!("{{1,2,1,2},{3,4,3,4},{5,6,5,6}}", array.to_string());
+    /// ```
+    pub fn concat<I>(axis: usize, arrays: I) -> Result<Array<T>, ArrayError>
+    where
+        I: IntoIterator<Item = Array<T>>,
+    {
+        let mut arrays = arrays.into_iter().filter(|a| !a.dims.is_empty());
+        let first = match arrays.next() {
+            Some(first) => first,
+            None => return Ok(Array::from_parts(vec![], vec![])),
+        };
+        if axis == 0 {
+            arrays.try_fold(first, Array::cat)
+        } else {
+            Array::concat_inner(axis, first, arrays)
+        }
+    }
+
+    fn cat(mut self, other: Array<T>) -> Result<Array<T>, ArrayError> {
+        if self.dims.len() == other.dims.len() {
+            if self.dims[1..] != other.dims[1..] {
+                let mut expected = self.dims.clone();
+                expected[0] = other.dims[0];
+                return Err(ArrayError::ShapeMismatch {
+                    expected,
+                    actual: other.dims,
+                });
+            }
+            self.dims[0].len += other.dims[0].len;
+            check_dims(&self.dims)?;
+            self.data.extend(other.data);
+            Ok(self)
+        } else if self.dims.len() == other.dims.len() + 1 {
+            self.try_push(other)?;
+            Ok(self)
+        } else if self.dims.len() + 1 == other.dims.len() {
+            if self.dims[..] != other.dims[1..] {
+                return Err(ArrayError::ShapeMismatch {
+                    expected: other.dims[1..].to_vec(),
+                    actual: self.dims,
+                });
+            }
+            let mut dims = other.dims;
+            dims[0].len += 1;
+            check_dims(&dims)?;
+            self.data.extend(other.data);
+            Ok(Array {
+                dims,
+                data: self.data,
+            })
+        } else {
+            Err(ArrayError::ShapeMismatch {
+                expected: self.dims,
+                actual: other.dims,
+            })
+        }
+    }
+
+    fn concat_inner<I>(axis: usize, first: Array<T>, rest: I) -> Result<Array<T>, ArrayError>
+    where
+        I: Iterator<Item = Array<T>>,
+    {
+        if axis >= first.dims.len() {
+            return Err(ArrayError::InvalidAxis {
+                axis,
+                dimensions: first.dims.len(),
+            });
+        }
+
+        let mut dims = first.dims.clone();
+        let mut arrays = vec![first];
+        for array in rest {
+            let mut expected = dims.clone();
+            if let Some(dim) = array.dims.get(axis) {
+                expected[axis] = *dim;
+            }
+            if array.dims != expected {
+                return Err(ArrayError::ShapeMismatch {
+                    expected,
+                    actual: array.dims,
+                });
+            }
+            dims[axis].len = dims[axis].len.saturating_add(array.dims[axis].len);
+            arrays.push(array);
+        }
+        check_dims(&dims)?;
+
+        let inner = dims[axis + 1..]
+            .iter()
+            .map(|d| d.len as usize)
+            .product::<usize>();
+        let outer = dims[..axis]
+            .iter()
+            .map(|d| d.len as usize)
+            .product::<usize>();
+        let len = arrays.iter().map(|a| a.data.len()).sum();
+        let mut chunks = arrays
+            .into_iter()
+            .map(|a| {
+                let chunk = a.dims[axis].len as usize * inner;
+                (chunk, a.data.into_iter())
+            })
+            .collect::<Vec<_>>();
+
+        let mut data = Vec::with_capacity(len);
+        for _ in 0..outer {
+            for (chunk, it) in &mut chunks {
+                data.extend(it.by_ref().take(*chunk));
+            }
+        }
+        Ok(Array { dims, data })
     }
 
     /// Returns the dimensions of this array.
@@ -134,17 +348,339 @@ impl<T> Array<T> {
         &self.dims
     }
 
-    fn shift_idx(&self, indices: &[i32]) -> i32 {
-        assert_eq!(self.dims.len(), indices.len());
-        self.dims
+    /// Replaces the dimensions of this array without moving its elements.
+    ///
+    /// Returns an error if the new dimensions do not specify the same number
+    /// of elements as the array contains. The array is left unchanged if an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::{Array, Dimension};
+    /// let mut array = Array::from_vec(vec![1i32, 2, 3, 4], 1);
+    /// array
+    ///     .reshape(vec![
+    ///         Dimension { len: 2, lower_bound: 1 },
+    ///         Dimension { len: 2, lower_bound: 1 },
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!("{{1,2},{3,4}}", array.to_string());
+    /// ```
+    pub fn reshape(&mut self, dimensions: Vec<Dimension>) -> Result<(), ArrayError> {
+        self.dims = check_size(dimensions, self.data.len())?;
+        Ok(())
+    }
+
+    /// Replaces the lower bounds of the dimensions of this array, keeping
+    /// their lengths.
+    ///
+    /// Returns an error if the number of lower bounds does not match the
+    /// number of dimensions, or if an upper bound would not fit in an `i32`.
+    /// The array is left unchanged if an error is returned.
+    pub fn set_lower_bounds(&mut self, lower_bounds: &[i32]) -> Result<(), ArrayError> {
+        if lower_bounds.len() != self.dims.len() {
+            return Err(ArrayError::DimensionMismatch {
+                expected: self.dims.len(),
+                actual: lower_bounds.len(),
+            });
+        }
+        let mut dims = self.dims.clone();
+        for (dim, &lower_bound) in dims.iter_mut().zip(lower_bounds) {
+            dim.lower_bound = lower_bound;
+        }
+        check_dims(&dims)?;
+        self.dims = dims;
+        Ok(())
+    }
+
+    /// Sets the lower bounds of all dimensions of this array to 1, the
+    /// Postgres default.
+    pub fn rebase_to_one(&mut self) {
+        for dim in &mut self.dims {
+            dim.lower_bound = 1;
+        }
+    }
+
+    fn shift_idx(&self, indices: &[i32]) -> Result<i32, ArrayError> {
+        if self.dims.len() != indices.len() {
+            return Err(ArrayError::DimensionMismatch {
+                expected: self.dims.len(),
+                actual: indices.len(),
+            });
+        }
+        let mut acc = 0;
+        let mut stride = 1;
+        for (axis, (dim, &idx)) in self.dims.iter().zip(indices).enumerate().rev() {
+            let shifted = dim.shift(idx).ok_or(ArrayError::OutOfBounds {
+                axis,
+                dimension: *dim,
+                index: idx,
+            })?;
+            acc += shifted * stride;
+            stride *= dim.len;
+        }
+        Ok(acc)
+    }
+
+    /// Returns a reference to the element at the specified index, or `None`
+    /// if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// let array = Array::from_vec(vec![0i32, 1, 2], 1);
+    /// assert_eq!(Some(&2), array.get(3));
+    /// assert_eq!(None, array.get(4));
+    /// assert_eq!(None, array.get((1, 1)));
+    /// ```
+    pub fn get<I: ArrayIndex>(&self, idx: I) -> Option<&T> {
+        let idx = idx.try_index(self).ok()?;
+        self.data.get(idx as usize)
+    }
+
+    /// Returns a mutable reference to the element at the specified index, or
+    /// `None` if the index is out of bounds.
+    pub fn get_mut<I: ArrayIndex>(&mut self, idx: I) -> Option<&mut T> {
+        let idx = idx.try_index(self).ok()?;
+        self.data.get_mut(idx as usize)
+    }
+
+    /// Determines if the specified index corresponds to an in-bounds element
+    /// of the array.
+    pub fn contains_index<I: ArrayIndex>(&self, idx: I) -> bool {
+        self.get(idx).is_some()
+    }
+
+    /// Returns a view of the entire array.
+    pub fn view(&self) -> ArrayView<'_, T> {
+        ArrayView::new(&self.data, self.dims.clone(), &strides(&self.dims))
+    }
+
+    /// Returns a mutable view of the entire array.
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T> {
+        let strides = strides(&self.dims);
+        ArrayViewMut::new(&mut self.data, self.dims.clone(), &strides)
+    }
+
+    /// Returns a view of a subarray, following the semantics of Postgres
+    /// array slices.
+    ///
+    /// `ranges` specifies the subscripts to select from each dimension,
+    /// starting with the first. Dimensions without a range are selected in
+    /// full, and ranges extending past the bounds of their dimension are
+    /// clamped to it. If any range selects no elements, or there are more
+    /// ranges than dimensions, the view is empty. As in Postgres, the lower
+    /// bounds of the view's dimensions are all 1.
+    ///
+    /// Ranges of different types can be combined by passing
+    /// `(Bound<i32>, Bound<i32>)` pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// // The equivalent of `'{{1,2,3},{4,5,6}}'::int[]`
+    /// let mut array = Array::from_vec(vec![1i32, 2, 3], 1);
+    /// array.wrap(1);
+    /// array.push(Array::from_vec(vec![4, 5, 6], 1));
+    ///
+    /// // The equivalent of `array[1:2][2:3]`
+    /// let view = array.slice(&[1..=2, 2..=3]);
+    /// assert_eq!("{{2,3},{5,6}}", view.to_string());
+    /// assert_eq!(6, view[(2, 2)]);
+    /// ```
+    pub fn slice<R: RangeBounds<i32>>(&self, ranges: &[R]) -> ArrayView<'_, T> {
+        let (dims, start, strides) = self.slice_layout(ranges);
+        ArrayView::new(&self.data[start..], dims, &strides)
+    }
+
+    /// Returns a mutable view of a subarray, following the semantics of
+    /// Postgres array slices.
+    ///
+    /// See `slice` for details.
+    pub fn slice_mut<R: RangeBounds<i32>>(&mut self, ranges: &[R]) -> ArrayViewMut<'_, T> {
+        let (dims, start, strides) = self.slice_layout(ranges);
+        ArrayViewMut::new(&mut self.data[start..], dims, &strides)
+    }
+
+    /// Returns the dimensions of a slice, the offset of its first element
+    /// and the strides of its dimensions.
+    fn slice_layout<R: RangeBounds<i32>>(
+        &self,
+        ranges: &[R],
+    ) -> (Vec<Dimension>, usize, Vec<usize>) {
+        if ranges.len() > self.dims.len() {
+            return (vec![], 0, vec![]);
+        }
+
+        let mut bounds = Vec::with_capacity(self.dims.len());
+        for (i, dim) in self.dims.iter().enumerate() {
+            let lower = i64::from(dim.lower_bound);
+            let upper = lower + i64::from(dim.len) - 1;
+            let (start, end) = match ranges.get(i) {
+                Some(range) => {
+                    let start = match range.start_bound() {
+                        Bound::Included(&idx) => i64::from(idx),
+                        Bound::Excluded(&idx) => i64::from(idx) + 1,
+                        Bound::Unbounded => lower,
+                    };
+                    let end = match range.end_bound() {
+                        Bound::Included(&idx) => i64::from(idx),
+                        Bound::Excluded(&idx) => i64::from(idx) - 1,
+                        Bound::Unbounded => upper,
+                    };
+                    (cmp::max(start, lower), cmp::min(end, upper))
+                }
+                None => (lower, upper),
+            };
+            if start > end {
+                return (vec![], 0, vec![]);
+            }
+            bounds.push(((start - lower) as usize, (end - start + 1) as usize));
+        }
+
+        let dims = bounds
             .iter()
-            .zip(indices.iter().cloned())
-            .rev()
-            .fold((0, 1), |(acc, stride), (dim, idx)| {
-                let shifted = dim.shift(idx);
-                (acc + shifted * stride, dim.len * stride)
+            .map(|&(_, len)| Dimension {
+                len: len as i32,
+                lower_bound: 1,
             })
-            .0
+            .collect();
+        let strides = strides(&self.dims);
+        let start = bounds
+            .iter()
+            .zip(&strides)
+            .map(|(&(start, _), stride)| start * stride)
+            .sum();
+        (dims, start, strides)
+    }
+
+    /// Returns the distance in the data vector between consecutive elements
+    /// of each dimension.
+    fn strides(&self) -> Vec<usize> {
+        strides(&self.dims)
+    }
+
+    /// Builds a new array with the specified dimensions from the elements at
+    /// the specified offsets, which must be distinct.
+    fn gather(self, dims: Vec<Dimension>, offsets: &[usize]) -> Array<T> {
+        let mut data = self.data.into_iter().map(Some).collect::<Vec<_>>();
+        Array {
+            dims,
+            data: offsets.iter().map(|&o| data[o].take().unwrap()).collect(),
+        }
+    }
+
+    /// Returns an iterator over the subarrays along the first dimension of
+    /// the array, paired with their subscripts in that dimension.
+    ///
+    /// Each subarray keeps the remaining dimensions of the array, including
+    /// their lower bounds. An empty array has no subarrays.
+    ///
+    /// Returns an error if the array has exactly one dimension, as its
+    /// subarrays would have no dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// let mut array = Array::from_vec(vec![1i32, 2], 1);
+    /// array.wrap(0);
+    /// array.push(Array::from_vec(vec![3, 4], 1));
+    ///
+    /// let rows = array
+    ///     .outer_iter()?
+    ///     .map(|(i, row)| format!("{}: {}", i, row))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec!["0: {1,2}", "1: {3,4}"], rows);
+    /// # Ok::<(), postgres_array::ArrayError>(())
+    /// ```
+    pub fn outer_iter(&self) -> Result<OuterIter<'_, T>, ArrayError> {
+        self.check_outer()?;
+        Ok(OuterIter::new(&self.data, &self.dims))
+    }
+
+    /// Like `outer_iter`, but returns mutable views of the subarrays.
+    pub fn outer_iter_mut(&mut self) -> Result<OuterIterMut<'_, T>, ArrayError> {
+        self.check_outer()?;
+        Ok(OuterIterMut::new(&mut self.data, &self.dims))
+    }
+
+    fn check_outer(&self) -> Result<(), ArrayError> {
+        if self.dims.len() == 1 {
+            return Err(ArrayError::InvalidAxis {
+                axis: 1,
+                dimensions: 1,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the one-dimensional lanes of the array along
+    /// the specified axis, paired with their subscripts in every other
+    /// dimension.
+    ///
+    /// Each lane keeps the dimension of the array at `axis`, including its
+    /// lower bound. An empty array has no lanes.
+    ///
+    /// Returns an error if the array is not empty and `axis` is not less
+    /// than its number of dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// let mut array = Array::from_vec(vec![1i32, 2], 1);
+    /// array.wrap(0);
+    /// array.push(Array::from_vec(vec![3, 4], 1));
+    ///
+    /// let columns = array
+    ///     .lanes(0)?
+    ///     .map(|(idx, column)| (idx, column.iter().sum::<i32>()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec![(vec![1], 4), (vec![2], 6)], columns);
+    /// # Ok::<(), postgres_array::ArrayError>(())
+    /// ```
+    pub fn lanes(&self, axis: usize) -> Result<Lanes<'_, T>, ArrayError> {
+        self.check_axis(axis)?;
+        Ok(Lanes::new(&self.data, &self.dims, axis))
+    }
+
+    /// Like `lanes`, but returns mutable views of the lanes.
+    pub fn lanes_mut(&mut self, axis: usize) -> Result<LanesMut<'_, T>, ArrayError> {
+        self.check_axis(axis)?;
+        Ok(LanesMut::new(&mut self.data, &self.dims, axis))
+    }
+
+    /// Returns an iterator over the lanes of the array along its last
+    /// dimension.
+    ///
+    /// For a two-dimensional array, these are its rows.
+    pub fn rows(&self) -> Lanes<'_, T> {
+        Lanes::new(&self.data, &self.dims, self.dims.len().saturating_sub(1))
+    }
+
+    /// Like `rows`, but returns mutable views of the lanes.
+    pub fn rows_mut(&mut self) -> LanesMut<'_, T> {
+        let axis = self.dims.len().saturating_sub(1);
+        LanesMut::new(&mut self.data, &self.dims, axis)
+    }
+
+    fn check_axis(&self, axis: usize) -> Result<(), ArrayError> {
+        if !self.data.is_empty() && axis >= self.dims.len() {
+            return Err(ArrayError::InvalidAxis {
+                axis,
+                dimensions: self.dims.len(),
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn data(&self) -> &[T] {
+        &self.data
     }
 
     /// Returns an iterator over references to the elements of the array in the
@@ -163,11 +699,335 @@ impl<T> Array<T> {
         }
     }
 
+    /// Returns an iterator over the subscripts of the elements of the array
+    /// in the higher-dimensional equivalent of row-major order.
+    ///
+    /// This is the equivalent of the Postgres `generate_subscripts` function
+    /// applied to every dimension at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// let mut array = Array::from_vec(vec!["a", "b"], 0);
+    /// array.wrap(1);
+    ///
+    /// let indices = array.indices().collect::<Vec<_>>();
+    /// assert_eq!(vec![vec![1, 0], vec![1, 1]], indices);
+    /// ```
+    pub fn indices(&self) -> Indices {
+        Indices::new(&self.dims, self.data.len())
+    }
+
+    /// Returns an iterator over the subscripts of the elements of the array
+    /// paired with references to the elements, in the higher-dimensional
+    /// equivalent of row-major order.
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter {
+            indices: self.indices(),
+            inner: self.data.iter(),
+        }
+    }
+
+    /// Returns an iterator over the subscripts of the elements of the array
+    /// paired with mutable references to the elements, in the
+    /// higher-dimensional equivalent of row-major order.
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T> {
+        IndexedIterMut {
+            indices: self.indices(),
+            inner: self.data.iter_mut(),
+        }
+    }
+
+    /// Consumes the array, returning an iterator over the subscripts of its
+    /// elements paired with the elements, in the higher-dimensional
+    /// equivalent of row-major order.
+    pub fn into_indexed_iter(self) -> IntoIndexedIter<T> {
+        IntoIndexedIter {
+            indices: self.indices(),
+            inner: self.data.into_iter(),
+        }
+    }
+
+    /// Consumes the array, returning a new array with the same dimensions
+    /// containing the result of applying `f` to each element.
+    pub fn map<U, F>(self, f: F) -> Array<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Array {
+            dims: self.dims,
+            data: self.data.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Like `map`, but `f` may fail.
+    ///
+    /// Elements are mapped in the higher-dimensional equivalent of row-major
+    /// order, stopping at the first error, which is returned along with the
+    /// subscripts of the element that produced it.
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<Array<U>, MapError<E>>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        for v in self.data {
+            match f(v) {
+                Ok(v) => data.push(v),
+                Err(error) => {
+                    return Err(MapError {
+                        index: subscripts(&self.dims, data.len()),
+                        error,
+                    })
+                }
+            }
+        }
+        Ok(Array {
+            dims: self.dims,
+            data,
+        })
+    }
+
+    /// Like `map`, but `f` is also passed the subscripts of each element.
+    pub fn map_with_index<U, F>(self, mut f: F) -> Array<U>
+    where
+        F: FnMut(&[i32], T) -> U,
+    {
+        let data = self
+            .indices()
+            .zip(self.data)
+            .map(|(idx, v)| f(&idx, v))
+            .collect();
+        Array {
+            dims: self.dims,
+            data,
+        }
+    }
+
+    /// Consumes the array, returning a new array with the same dimensions
+    /// containing the result of applying `f` to each element and the
+    /// corresponding element of `other`.
+    ///
+    /// Returns an error if the dimensions of the two arrays, including their
+    /// lower bounds, are not identical.
+    pub fn zip_with<U, V, F>(self, other: &Array<U>, mut f: F) -> Result<Array<V>, ArrayError>
+    where
+        F: FnMut(T, &U) -> V,
+    {
+        if self.dims != other.dims {
+            return Err(ArrayError::ShapeMismatch {
+                expected: self.dims,
+                actual: other.dims.clone(),
+            });
+        }
+        let data = self
+            .data
+            .into_iter()
+            .zip(&other.data)
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Ok(Array {
+            dims: self.dims,
+            data,
+        })
+    }
+
+    /// Consumes the array, returning a new array with the order of its
+    /// dimensions reversed.
+    ///
+    /// For a two-dimensional array, this is the matrix transpose. The lower
+    /// bounds of the dimensions move along with them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use postgres_array::Array;
+    /// let mut array = Array::from_vec(vec![1i32, 2, 3], 1);
+    /// array.wrap(0);
+    /// array.push(Array::from_vec(vec![4, 5, 6], 1));
+    /// assert_eq!("[0:1][1:3]={{1,2,3},{4,5,6}}", array.to_string());
+    ///
+    /// let array = array.transpose();
+    /// assert_eq!("[1:3][0:1]={{1,4},{2,5},{3,6}}", array.to_string());
+    /// ```
+    pub fn transpose(self) -> Array<T> {
+        let axes = (0..self.dims.len()).rev().collect::<Vec<_>>();
+        self.permute(&axes)
+    }
+
+    /// Consumes the array, returning a new array with its dimensions
+    /// reordered.
+    ///
+    /// Dimension `i` of the new array is dimension `axes[i]` of this array,
+    /// including its lower bound. Returns an error if `axes` is not a
+    /// permutation of the array's axes.
+    pub fn permute_axes(self, axes: &[usize]) -> Result<Array<T>, ArrayError> {
+        let mut seen = vec![false; self.dims.len()];
+        let valid = axes.len() == self.dims.len()
+            && axes
+                .iter()
+                .all(|&axis| axis < seen.len() && !std::mem::replace(&mut seen[axis], true));
+        if !valid {
+            return Err(ArrayError::InvalidPermutation {
+                expected: self.dims.len(),
+                actual: axes.to_vec(),
+            });
+        }
+        Ok(self.permute(axes))
+    }
+
+    fn permute(self, axes: &[usize]) -> Array<T> {
+        let strides = self.strides();
+        let dims = axes.iter().map(|&axis| self.dims[axis]).collect::<Vec<_>>();
+        let lens = dims.iter().map(|d| d.len as usize).collect::<Vec<_>>();
+        let strides = axes
+            .iter()
+            .map(|&axis| strides[axis] as isize)
+            .collect::<Vec<_>>();
+        let offsets = strided_offsets(0, &lens, &strides);
+        self.gather(dims, &offsets)
+    }
+
+    /// Consumes the array, returning a new array with the order of the
+    /// elements along the specified axis reversed.
+    ///
+    /// The dimensions of the array are unchanged. Returns an error if `axis`
+    /// is not less than the number of dimensions of the array.
+    pub fn flip(self, axis: usize) -> Result<Array<T>, ArrayError> {
+        if axis >= self.dims.len() {
+            return Err(ArrayError::InvalidAxis {
+                axis,
+                dimensions: self.dims.len(),
+            });
+        }
+        let mut strides = self
+            .strides()
+            .iter()
+            .map(|&s| s as isize)
+            .collect::<Vec<_>>();
+        let lens = self.dims.iter().map(|d| d.len as usize).collect::<Vec<_>>();
+        let start = (lens[axis] as isize - 1) * strides[axis];
+        strides[axis] = -strides[axis];
+        let offsets = strided_offsets(start, &lens, &strides);
+        let dims = self.dims.clone();
+        Ok(self.gather(dims, &offsets))
+    }
+
     /// Returns the underlying data vector for this Array in the
     /// higher-dimensional equivalent of row-major order.
     pub fn into_inner(self) -> Vec<T> {
         self.data
     }
+
+    pub(crate) fn into_parts(self) -> (Vec<Dimension>, Vec<T>) {
+        (self.dims, self.data)
+    }
+}
+
+/// Returns the distance between consecutive elements of each dimension of an
+/// array with the specified dimensions, stored in row-major order.
+pub(crate) fn strides(dims: &[Dimension]) -> Vec<usize> {
+    let mut strides = vec![1; dims.len()];
+    for i in (1..dims.len()).rev() {
+        strides[i - 1] = strides[i] * dims[i].len as usize;
+    }
+    strides
+}
+
+/// Returns the offsets of the elements of a strided box in the
+/// higher-dimensional equivalent of row-major order.
+fn strided_offsets(start: isize, lens: &[usize], strides: &[isize]) -> Vec<usize> {
+    let count = lens.iter().product();
+    if lens.is_empty() || count == 0 {
+        return vec![];
+    }
+
+    let mut offsets = Vec::with_capacity(count);
+    let mut pos = vec![0; lens.len()];
+    let mut offset = start;
+    loop {
+        offsets.push(offset as usize);
+
+        let mut axis = lens.len();
+        loop {
+            if axis == 0 {
+                return offsets;
+            }
+            axis -= 1;
+            pos[axis] += 1;
+            offset += strides[axis];
+            if pos[axis] < lens[axis] {
+                break;
+            }
+            offset -= strides[axis] * lens[axis] as isize;
+            pos[axis] = 0;
+        }
+    }
+}
+
+/// Checks that `dims` are within the Postgres array limits, returning the
+/// number of elements they specify.
+pub(crate) fn check_dims(dims: &[Dimension]) -> Result<usize, ArrayError> {
+    if dims.len() > MAX_DIMENSIONS {
+        return Err(ArrayError::TooManyDimensions { len: dims.len() });
+    }
+    for (axis, dim) in dims.iter().enumerate() {
+        if i64::from(dim.lower_bound) + i64::from(dim.len) - 1 > i64::from(i32::MAX) {
+            return Err(ArrayError::BoundOverflow {
+                axis,
+                dimension: *dim,
+            });
+        }
+    }
+    if dims.is_empty() {
+        return Ok(0);
+    }
+    let len = dims
+        .iter()
+        .fold(1usize, |acc, d| acc.saturating_mul(d.len as usize));
+    if len > MAX_ELEMENTS {
+        return Err(ArrayError::TooManyElements { len });
+    }
+    Ok(len)
+}
+
+/// Returns the dimension of a one-dimensional array of `len` elements.
+pub(crate) fn vec_dimension(len: usize, lower_bound: i32) -> Result<Dimension, ArrayError> {
+    if len > MAX_ELEMENTS {
+        return Err(ArrayError::TooManyElements { len });
+    }
+    let dim = Dimension {
+        len: len as i32,
+        lower_bound,
+    };
+    check_dims(&[dim])?;
+    Ok(dim)
+}
+
+/// Checks that `dims` are within the Postgres array limits and specify `len`
+/// elements.
+fn check_size(dims: Vec<Dimension>, len: usize) -> Result<Vec<Dimension>, ArrayError> {
+    let expected = check_dims(&dims)?;
+    if len != expected {
+        return Err(ArrayError::SizeMismatch {
+            dimensions: dims,
+            expected,
+            actual: len,
+        });
+    }
+    Ok(dims)
+}
+
+/// Returns the subscripts of the element at the specified offset into the
+/// data of an array with the specified dimensions.
+fn subscripts(dims: &[Dimension], mut offset: usize) -> Vec<i32> {
+    let mut idx = vec![0; dims.len()];
+    for (idx, dim) in idx.iter_mut().zip(dims).rev() {
+        let len = dim.len as usize;
+        *idx = dim.lower_bound + (offset % len) as i32;
+        offset /= len;
+    }
+    idx
 }
 
 /// A trait implemented by types that can index into an `Array`.
@@ -179,34 +1039,47 @@ pub trait ArrayIndex {
     ///
     /// Panics if the value of `self` does not correspond to an in-bounds
     /// element of the `Array`.
-    fn index<T>(&self, array: &Array<T>) -> i32;
+    fn index<T>(&self, array: &Array<T>) -> i32 {
+        self.try_index(array).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `index`, but returns an error instead of panicking if the value
+    /// of `self` does not correspond to an in-bounds element of the `Array`.
+    fn try_index<T>(&self, array: &Array<T>) -> Result<i32, ArrayError>;
 }
 
-impl<'a> ArrayIndex for &'a [i32] {
-    fn index<T>(&self, array: &Array<T>) -> i32 {
-        array.shift_idx(*self)
+impl ArrayIndex for &[i32] {
+    fn try_index<T>(&self, array: &Array<T>) -> Result<i32, ArrayError> {
+        array.shift_idx(self)
     }
 }
 
 impl ArrayIndex for i32 {
-    fn index<T>(&self, array: &Array<T>) -> i32 {
+    fn try_index<T>(&self, array: &Array<T>) -> Result<i32, ArrayError> {
         let slice: &[i32] = &[*self];
-        ArrayIndex::index(&slice, array)
+        slice.try_index(array)
     }
 }
 
 macro_rules! tuple_impl {
     ($($name:ident : $t:ty),+) => {
         impl ArrayIndex for ($($t,)+) {
-            fn index<T>(&self, array: &Array<T>) -> i32 {
+            fn try_index<T>(&self, array: &Array<T>) -> Result<i32, ArrayError> {
                 let ($($name,)+) = *self;
                 let slice: &[i32] = &[$($name),+];
-                ArrayIndex::index(&slice, array)
+                slice.try_index(array)
             }
         }
     }
 }
 
+impl<const N: usize> ArrayIndex for [i32; N] {
+    fn try_index<T>(&self, array: &Array<T>) -> Result<i32, ArrayError> {
+        let slice: &[i32] = self;
+        slice.try_index(array)
+    }
+}
+
 tuple_impl!(a: i32);
 tuple_impl!(a: i32, b: i32);
 tuple_impl!(a: i32, b: i32, c: i32);
@@ -394,3 +1267,111 @@ impl<T> ExactSizeIterator for IntoIter<T> {
         self.inner.len()
     }
 }
+
+/// An iterator over the subscripts of the elements of an `Array` in the
+/// higher-dimensional equivalent of row-major order.
+pub struct Indices {
+    dims: Vec<Dimension>,
+    next: Vec<i32>,
+    remaining: usize,
+}
+
+impl Indices {
+    fn new(dims: &[Dimension], len: usize) -> Indices {
+        Indices {
+            dims: dims.to_vec(),
+            next: dims.iter().map(|d| d.lower_bound).collect(),
+            remaining: len,
+        }
+    }
+}
+
+impl Iterator for Indices {
+    type Item = Vec<i32>;
+
+    fn next(&mut self) -> Option<Vec<i32>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let current = self.next.clone();
+        for (idx, dim) in self.next.iter_mut().zip(&self.dims).rev() {
+            if i64::from(*idx) + 1 < i64::from(dim.lower_bound) + i64::from(dim.len) {
+                *idx += 1;
+                break;
+            }
+            *idx = dim.lower_bound;
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Indices {}
+
+/// An iterator over the subscripts of the elements of an `Array` paired with
+/// references to the elements.
+pub struct IndexedIter<'a, T> {
+    indices: Indices,
+    inner: slice::Iter<'a, T>,
+}
+
+impl<'a, T: 'a> Iterator for IndexedIter<'a, T> {
+    type Item = (Vec<i32>, &'a T);
+
+    fn next(&mut self) -> Option<(Vec<i32>, &'a T)> {
+        Some((self.indices.next()?, self.inner.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IndexedIter<'a, T> {}
+
+/// An iterator over the subscripts of the elements of an `Array` paired with
+/// mutable references to the elements.
+pub struct IndexedIterMut<'a, T> {
+    indices: Indices,
+    inner: slice::IterMut<'a, T>,
+}
+
+impl<'a, T: 'a> Iterator for IndexedIterMut<'a, T> {
+    type Item = (Vec<i32>, &'a mut T);
+
+    fn next(&mut self) -> Option<(Vec<i32>, &'a mut T)> {
+        Some((self.indices.next()?, self.inner.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IndexedIterMut<'a, T> {}
+
+/// An iterator over the subscripts of the elements of an `Array` paired with
+/// the elements.
+pub struct IntoIndexedIter<T> {
+    indices: Indices,
+    inner: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIndexedIter<T> {
+    type Item = (Vec<i32>, T);
+
+    fn next(&mut self) -> Option<(Vec<i32>, T)> {
+        Some((self.indices.next()?, self.inner.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIndexedIter<T> {}
@@ -0,0 +1,226 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::array::{MAX_DIMENSIONS, MAX_ELEMENTS};
+use crate::Dimension;
+
+/// An error returned by the fallible `Array` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArrayError {
+    /// The number of elements provided did not match the number of elements
+    /// specified by the dimensions.
+    SizeMismatch {
+        /// The dimensions of the array.
+        dimensions: Vec<Dimension>,
+        /// The number of elements specified by the dimensions.
+        expected: usize,
+        /// The number of elements provided.
+        actual: usize,
+    },
+    /// An array did not have the dimensions required by the operation.
+    ShapeMismatch {
+        /// The required dimensions.
+        expected: Vec<Dimension>,
+        /// The dimensions of the array provided.
+        actual: Vec<Dimension>,
+    },
+    /// The number of subscripts did not match the number of dimensions of
+    /// the array.
+    DimensionMismatch {
+        /// The number of dimensions of the array.
+        expected: usize,
+        /// The number of subscripts provided.
+        actual: usize,
+    },
+    /// A subscript was outside the bounds of its dimension.
+    OutOfBounds {
+        /// The position of the dimension in the array's dimensions.
+        axis: usize,
+        /// The dimension the subscript was applied to.
+        dimension: Dimension,
+        /// The subscript.
+        index: i32,
+    },
+    /// An axis was not less than the number of dimensions of the array.
+    InvalidAxis {
+        /// The axis.
+        axis: usize,
+        /// The number of dimensions of the array.
+        dimensions: usize,
+    },
+    /// A list of axes was not a permutation of the axes of the array.
+    InvalidPermutation {
+        /// The number of dimensions of the array.
+        expected: usize,
+        /// The axes provided.
+        actual: Vec<usize>,
+    },
+    /// An array did not have the number of dimensions required by a
+    /// fixed-rank array type.
+    RankMismatch {
+        /// The required number of dimensions.
+        expected: usize,
+        /// The number of dimensions of the array.
+        actual: usize,
+    },
+    /// A row of a nested collection did not have the same length as the
+    /// first row at the same depth.
+    Jagged {
+        /// The position of the row in the collection, starting from 0 at
+        /// each level of nesting.
+        index: Vec<usize>,
+        /// The length of the first row.
+        expected: usize,
+        /// The length of the row.
+        actual: usize,
+    },
+    /// An operation which requires an array with at least one dimension was
+    /// applied to an empty array.
+    NoDimensions,
+    /// An array had more than `MAX_DIMENSIONS` dimensions.
+    TooManyDimensions {
+        /// The number of dimensions.
+        len: usize,
+    },
+    /// An array had more than `MAX_ELEMENTS` elements.
+    TooManyElements {
+        /// The number of elements, saturated at `usize::MAX`.
+        len: usize,
+    },
+    /// The upper bound of a dimension did not fit in an `i32`.
+    BoundOverflow {
+        /// The position of the dimension in the array's dimensions.
+        axis: usize,
+        /// The dimension.
+        dimension: Dimension,
+    },
+}
+
+impl fmt::Display for ArrayError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayError::SizeMismatch {
+                dimensions,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "size mismatch: dimensions {} specify {} elements but {} were provided",
+                Dims(dimensions),
+                expected,
+                actual
+            ),
+            ArrayError::ShapeMismatch { expected, actual } => write!(
+                fmt,
+                "differently shaped arrays: expected dimensions {} but found {}",
+                Dims(expected),
+                Dims(actual)
+            ),
+            ArrayError::DimensionMismatch { expected, actual } => write!(
+                fmt,
+                "wrong number of subscripts: expected {} but found {}",
+                expected, actual
+            ),
+            ArrayError::OutOfBounds {
+                axis,
+                dimension,
+                index,
+            } => write!(
+                fmt,
+                "out of bounds array access: subscript {} is outside {} of dimension {}",
+                index,
+                Dims(&[*dimension]),
+                axis
+            ),
+            ArrayError::InvalidAxis { axis, dimensions } => write!(
+                fmt,
+                "axis {} is out of range for an array of {} dimensions",
+                axis, dimensions
+            ),
+            ArrayError::InvalidPermutation { expected, actual } => write!(
+                fmt,
+                "axes {:?} are not a permutation of the axes of an array of {} dimensions",
+                actual, expected
+            ),
+            ArrayError::RankMismatch { expected, actual } => write!(
+                fmt,
+                "wrong number of array dimensions: expected {} but found {}",
+                expected, actual
+            ),
+            ArrayError::Jagged {
+                index,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "jagged array: row {:?} has length {} but the first row has length {}",
+                index, actual, expected
+            ),
+            ArrayError::NoDimensions => write!(fmt, "array has no dimensions"),
+            ArrayError::TooManyDimensions { len } => write!(
+                fmt,
+                "number of array dimensions ({}) exceeds the maximum allowed ({})",
+                len, MAX_DIMENSIONS
+            ),
+            ArrayError::TooManyElements { len } => write!(
+                fmt,
+                "array size ({} elements) exceeds the maximum allowed ({})",
+                len, MAX_ELEMENTS
+            ),
+            ArrayError::BoundOverflow { axis, dimension } => write!(
+                fmt,
+                "array upper bound is too large: dimension {} is {}",
+                axis,
+                Dims(&[*dimension])
+            ),
+        }
+    }
+}
+
+impl Error for ArrayError {}
+
+/// An error returned by `Array::try_map`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapError<E> {
+    /// The subscripts of the element that could not be mapped.
+    pub index: Vec<i32>,
+    /// The error returned by the mapping function.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for MapError<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "error mapping element ")?;
+        for idx in &self.index {
+            write!(fmt, "[{}]", idx)?;
+        }
+        write!(fmt, ": {}", self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for MapError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Formats dimensions in the `[lower:upper]` notation used by Postgres.
+pub(crate) struct Dims<'a>(pub(crate) &'a [Dimension]);
+
+impl fmt::Display for Dims<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(fmt, "[]");
+        }
+        for dim in self.0 {
+            write!(
+                fmt,
+                "[{}:{}]",
+                dim.lower_bound,
+                i64::from(dim.lower_bound) + i64::from(dim.len) - 1
+            )?;
+        }
+        Ok(())
+    }
+}
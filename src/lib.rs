@@ -3,9 +3,29 @@
 
 #[doc(inline)]
 pub use crate::array::Array;
+#[doc(inline)]
+pub use crate::array_n::{Array1, Array2, ArrayN};
+#[doc(inline)]
+pub use crate::array_ref::ArrayRef;
+#[doc(inline)]
+pub use crate::dynamic::DynArray;
+pub use crate::error::{ArrayError, MapError};
+#[doc(inline)]
+pub use crate::nullable::NullableArray;
+#[doc(inline)]
+pub use crate::view::{ArrayView, ArrayViewMut};
 
 pub mod array;
+pub mod array_n;
+pub mod array_ref;
+pub mod dynamic;
+mod error;
 mod impls;
+pub mod nested;
+pub mod nullable;
+pub mod primitive;
+pub mod text;
+pub mod view;
 
 /// Information about a dimension of an array.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,16 +37,12 @@ pub struct Dimension {
 }
 
 impl Dimension {
-    fn shift(&self, idx: i32) -> i32 {
-        let offset = self.lower_bound;
-        assert!(idx >= offset, "out of bounds array access");
-        assert!(
-            offset >= 0 || idx <= 0 || i32::max_value() - (-offset) >= idx,
-            "out of bounds array access"
-        );
-        match idx.checked_sub(offset) {
-            Some(shifted) => shifted,
-            None => panic!("out of bounds array access"),
+    fn shift(&self, idx: i32) -> Option<i32> {
+        let shifted = i64::from(idx) - i64::from(self.lower_bound);
+        if shifted >= 0 && shifted < i64::from(self.len) {
+            Some(shifted as i32)
+        } else {
+            None
         }
     }
 }
@@ -34,6 +50,8 @@ impl Dimension {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::array::ArrayIndex;
+    use std::ops::Bound;
 
     #[test]
     fn test_from_vec() {
@@ -94,6 +112,187 @@ mod tests {
         a.push(b);
     }
 
+    #[test]
+    fn test_try_push_wrong_dims() {
+        let mut a = Array::from_vec(vec![1i32], -1);
+        a.wrap(1);
+        let err = a.try_push(Array::from_vec(vec![1, 2], -1)).unwrap_err();
+        assert_eq!(
+            err,
+            ArrayError::ShapeMismatch {
+                expected: vec![Dimension {
+                    len: 1,
+                    lower_bound: -1,
+                }],
+                actual: vec![Dimension {
+                    len: 2,
+                    lower_bound: -1,
+                }],
+            }
+        );
+        assert_eq!(&[1], &a.into_inner()[..]);
+    }
+
+    #[test]
+    fn test_try_from_parts() {
+        let dims = vec![
+            Dimension {
+                len: 2,
+                lower_bound: 1,
+            },
+            Dimension {
+                len: 2,
+                lower_bound: 1,
+            },
+        ];
+        let err = Array::try_from_parts(vec![1i32, 2, 3], dims.clone()).unwrap_err();
+        assert_eq!(
+            err,
+            ArrayError::SizeMismatch {
+                dimensions: dims.clone(),
+                expected: 4,
+                actual: 3,
+            }
+        );
+        assert_eq!(
+            "size mismatch: dimensions [1:2][1:2] specify 4 elements but 3 were provided",
+            err.to_string()
+        );
+
+        let a = Array::try_from_parts(vec![1i32, 2, 3, 4], dims).unwrap();
+        assert_eq!(4, a[(2, 2)]);
+    }
+
+    #[test]
+    fn test_limits() {
+        let dim = |len, lower_bound| Dimension { len, lower_bound };
+
+        let mut a = Array::from_vec(vec![1i32], 1);
+        for _ in 1..array::MAX_DIMENSIONS {
+            a.wrap(1);
+        }
+        assert_eq!(Err(ArrayError::TooManyDimensions { len: 7 }), a.try_wrap(1));
+        assert_eq!(6, a.dimensions().len());
+        assert_eq!(
+            ArrayError::TooManyDimensions { len: 7 },
+            Array::try_from_parts(vec![1i32], vec![dim(1, 1); 7]).unwrap_err()
+        );
+
+        let err =
+            Array::try_from_parts(vec![0u8; 0], vec![dim(65536, 1), dim(65536, 1)]).unwrap_err();
+        assert_eq!(ArrayError::TooManyElements { len: 1 << 32 }, err);
+        assert_eq!(
+            "array size (4294967296 elements) exceeds the maximum allowed (134217727)",
+            err.to_string()
+        );
+
+        let err = Array::try_from_vec(vec![1i32, 2], i32::MAX).unwrap_err();
+        assert_eq!(
+            ArrayError::BoundOverflow {
+                axis: 0,
+                dimension: dim(2, i32::MAX),
+            },
+            err
+        );
+        assert_eq!(
+            "array upper bound is too large: dimension 0 is [2147483647:2147483648]",
+            err.to_string()
+        );
+
+        let mut a = Array::from_vec(vec![1i32], i32::MAX);
+        assert!(a.try_push(Array::from_parts(vec![], vec![])).is_err());
+        a.wrap(i32::MAX);
+        assert!(a.try_push(Array::from_vec(vec![2], i32::MAX)).is_err());
+        assert_eq!(1, a.dimensions()[0].len);
+        let mut b = Array::from_vec(vec![1i32, 2], 1);
+        assert!(b.set_lower_bounds(&[i32::MAX]).is_err());
+        assert_eq!(1, b.dimensions()[0].lower_bound);
+        assert!(Array::concat(0, vec![b, Array::from_vec(vec![3], i32::MAX - 1)]).is_ok());
+    }
+
+    #[test]
+    fn test_try_wrap_empty() {
+        let mut a: Array<i32> = Array::from_parts(vec![], vec![]);
+        assert_eq!(Err(ArrayError::NoDimensions), a.try_wrap(1));
+        assert!(a.dimensions().is_empty());
+        a.wrap(1);
+        assert_eq!(1, a.dimensions().len());
+    }
+
+    #[test]
+    fn test_try_index() {
+        let mut a = Array::from_vec(vec![0i32, 1, 2], -1);
+        a.wrap(1);
+        assert_eq!(Ok(1), (1, 0).try_index(&a));
+        assert_eq!(
+            Err(ArrayError::OutOfBounds {
+                axis: 1,
+                dimension: Dimension {
+                    len: 3,
+                    lower_bound: -1,
+                },
+                index: 2,
+            }),
+            (1, 2).try_index(&a)
+        );
+        assert_eq!(
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 1,
+            }),
+            1.try_index(&a)
+        );
+
+        struct Last;
+
+        impl ArrayIndex for Last {
+            fn try_index<T>(&self, array: &Array<T>) -> Result<i32, ArrayError> {
+                match array.dimensions() {
+                    [dim] if dim.len > 0 => Ok(dim.len - 1),
+                    dims => Err(ArrayError::DimensionMismatch {
+                        expected: 1,
+                        actual: dims.len(),
+                    }),
+                }
+            }
+        }
+
+        assert_eq!(None, a.get(Last));
+        assert!(!a.contains_index(Last));
+        let b = Array::from_vec(vec![0i32, 1, 2], -1);
+        assert_eq!(Some(&2), b.get(Last));
+        assert_eq!(2, b[Last]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds array access")]
+    fn test_index_past_upper_bound() {
+        let mut a = Array::from_vec(vec![0i32, 1], 0);
+        a.wrap(0);
+        a.push(Array::from_vec(vec![2, 3], 0));
+        let _ = a[(0, 2)];
+    }
+
+    #[test]
+    fn test_get() {
+        let mut a = Array::from_vec(vec![0i32, 1], 0);
+        a.wrap(-1);
+        a.push(Array::from_vec(vec![2, 3], 0));
+        assert_eq!(Some(&3), a.get((0, 1)));
+        assert_eq!(Some(&0), a.get(&[-1, 0][..]));
+        assert_eq!(None, a.get((1, 1)));
+        assert_eq!(None, a.get((-2, 0)));
+        assert_eq!(None, a.get((0, 2)));
+        assert_eq!(None, a.get(0));
+        assert_eq!(None, a.get((0, 0, 0)));
+        assert!(a.contains_index((-1, 1)));
+        assert!(!a.contains_index((i32::MIN, i32::MAX)));
+
+        *a.get_mut((0, 0)).unwrap() = 4;
+        assert_eq!(4, a[(0, 0)]);
+        assert_eq!(None, a.get_mut((0, 2)));
+    }
+
     #[test]
     fn test_push_ok() {
         let mut a = Array::from_vec(vec![1i32, 2], 0);
@@ -133,6 +332,599 @@ mod tests {
         assert_eq!(3, a[(0, 0)]);
     }
 
+    fn matrix() -> Array<i32> {
+        // '[0:2][-1:1]={{1,2,3},{4,5,6},{7,8,9}}'
+        let mut a = Array::from_vec(vec![1i32, 2, 3], -1);
+        a.wrap(0);
+        a.push(Array::from_vec(vec![4, 5, 6], -1));
+        a.push(Array::from_vec(vec![7, 8, 9], -1));
+        a
+    }
+
+    #[test]
+    fn test_slice() {
+        let a = matrix();
+        let view = a.slice(&[1..=2, 0..=1]);
+        assert_eq!(
+            &[
+                Dimension {
+                    len: 2,
+                    lower_bound: 1,
+                },
+                Dimension {
+                    len: 2,
+                    lower_bound: 1,
+                },
+            ][..],
+            view.dimensions()
+        );
+        assert_eq!(vec![&5, &6, &8, &9], view.iter().collect::<Vec<_>>());
+        assert_eq!(5, view[(1, 1)]);
+        assert_eq!(Some(&9), view.get((2, 2)));
+        assert_eq!(None, view.get((0, 0)));
+        assert_eq!("{{5,6},{8,9}}", view.to_string());
+
+        let mut expected = Array::from_vec(vec![5, 6], 1);
+        expected.wrap(1);
+        expected.push(Array::from_vec(vec![8, 9], 1));
+        assert_eq!(expected, view.to_owned());
+    }
+
+    #[test]
+    fn test_slice_clamps() {
+        let a = matrix();
+        assert_eq!("{{4,5,6},{7,8,9}}", a.slice(&[1..=10]).to_string());
+        assert_eq!("{{1},{4}}", a.slice(&[-5..=1, i32::MIN..=-1]).to_string());
+        assert_eq!(
+            "{{2,3}}",
+            a.slice(&[
+                (Bound::Unbounded, Bound::Excluded(1)),
+                (Bound::Included(0), Bound::Unbounded)
+            ])
+            .to_string()
+        );
+        assert_eq!(a.slice(&[..]).iter().count(), 9);
+    }
+
+    #[test]
+    fn test_slice_empty() {
+        let a = matrix();
+        let (lo, hi) = (2, 1);
+        for view in [
+            a.slice(&[3..=4]),
+            a.slice(&[lo..=hi]),
+            a.slice(&[0..=0, 0..=0, 0..=0]),
+        ] {
+            assert!(view.dimensions().is_empty());
+            assert_eq!(0, view.iter().len());
+            assert_eq!("{}", view.to_string());
+        }
+
+        let a: Array<i32> = Array::from_parts(vec![], vec![]);
+        assert_eq!(0, a.slice(&[1..=1]).iter().len());
+    }
+
+    #[test]
+    fn test_view() {
+        let a = matrix();
+        let view = a.view();
+        assert_eq!(a.dimensions(), view.dimensions());
+        assert_eq!(4, view[(1, -1)]);
+        assert_eq!(a, view.to_owned());
+    }
+
+    #[test]
+    fn test_slice_mut() {
+        let mut a = matrix();
+        let mut view = a.slice_mut(&[0..=1, 1..=1]);
+        assert_eq!("{{3},{6}}", view.to_string());
+        view[(1, 1)] = 10;
+        *view.get_mut((2, 1)).unwrap() *= 10;
+        assert_eq!(None, view.get_mut((3, 1)));
+        assert_eq!(vec![&10, &60], view.iter().collect::<Vec<_>>());
+        for v in view.iter_mut() {
+            *v += 1;
+        }
+        assert_eq!("[0:2][-1:1]={{1,2,11},{4,5,61},{7,8,9}}", &format!("{}", a));
+
+        let mut view = a.view_mut();
+        view[(2, 1)] = 0;
+        assert_eq!(9, view.iter().count());
+        assert_eq!(0, a[(2, 1)]);
+    }
+
+    #[test]
+    fn test_outer_iter() {
+        let a = matrix();
+        let rows = a.outer_iter().unwrap().collect::<Vec<_>>();
+        assert_eq!(3, rows.len());
+        assert_eq!(0, rows[0].0);
+        assert_eq!("[-1:1]={1,2,3}", rows[0].1.to_string());
+        assert_eq!(2, rows[2].0);
+        assert_eq!(8, rows[2].1[0]);
+
+        let mut a = a;
+        a.wrap(1);
+        let mut it = a.outer_iter().unwrap();
+        assert_eq!(1, it.len());
+        let (idx, view) = it.next().unwrap();
+        assert_eq!(1, idx);
+        assert_eq!(&a.dimensions()[1..], view.dimensions());
+
+        for (i, view) in a
+            .outer_iter_mut()
+            .unwrap()
+            .next()
+            .unwrap()
+            .1
+            .into_iter()
+            .enumerate()
+        {
+            *view += i as i32;
+        }
+        assert_eq!(
+            "[1:1][0:2][-1:1]={{{1,3,5},{7,9,11},{13,15,17}}}",
+            a.to_string()
+        );
+
+        let mut a = matrix();
+        for (idx, mut row) in a.outer_iter_mut().unwrap() {
+            row[-1] = idx;
+        }
+        assert_eq!(
+            vec![&0, &1, &2],
+            a.lanes(0)
+                .unwrap()
+                .next()
+                .unwrap()
+                .1
+                .iter()
+                .collect::<Vec<_>>()
+        );
+
+        let a: Array<i32> = Array::from_parts(vec![], vec![]);
+        assert_eq!(0, a.outer_iter().unwrap().count());
+    }
+
+    #[test]
+    fn test_outer_iter_1d() {
+        let mut a = Array::from_vec(vec![1i32], 1);
+        let err = ArrayError::InvalidAxis {
+            axis: 1,
+            dimensions: 1,
+        };
+        assert_eq!(err, a.outer_iter().err().unwrap());
+        assert_eq!(err, a.outer_iter_mut().err().unwrap());
+    }
+
+    #[test]
+    fn test_lanes() {
+        let a = matrix();
+        let columns = a
+            .lanes(0)
+            .unwrap()
+            .map(|(idx, lane)| (idx, lane.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (vec![-1], "[0:2]={1,4,7}".to_string()),
+                (vec![0], "[0:2]={2,5,8}".to_string()),
+                (vec![1], "[0:2]={3,6,9}".to_string()),
+            ],
+            columns
+        );
+
+        let rows = a
+            .rows()
+            .map(|(idx, lane)| (idx, lane.to_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(3, rows.len());
+        assert_eq!((vec![2], Array::from_vec(vec![7, 8, 9], -1)), rows[2]);
+
+        let mut b = Array::from_vec((0..24).collect(), 0);
+        let dims = vec![
+            Dimension {
+                len: 2,
+                lower_bound: 1,
+            },
+            Dimension {
+                len: 3,
+                lower_bound: 0,
+            },
+            Dimension {
+                len: 4,
+                lower_bound: -1,
+            },
+        ];
+        b = Array::from_parts(b.into_inner(), dims);
+        let lanes = b.lanes(1).unwrap().collect::<Vec<_>>();
+        assert_eq!(8, lanes.len());
+        assert_eq!(vec![2, 1], lanes[6].0);
+        assert_eq!(vec![&14, &18, &22], lanes[6].1.iter().collect::<Vec<_>>());
+        for (idx, lane) in &lanes {
+            for (i, v) in lane.iter().enumerate() {
+                assert_eq!(*v, b[(idx[0], i as i32, idx[1])]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lanes_mut() {
+        let mut a = matrix();
+        for (idx, mut lane) in a.lanes_mut(0).unwrap() {
+            for v in lane.iter_mut() {
+                *v *= idx[0];
+            }
+        }
+        assert_eq!("[0:2][-1:1]={{-1,0,3},{-4,0,6},{-7,0,9}}", a.to_string());
+
+        for (idx, mut row) in a.rows_mut() {
+            row[1] = idx[0];
+        }
+        assert_eq!("[0:2][-1:1]={{-1,0,0},{-4,0,1},{-7,0,2}}", a.to_string());
+
+        let mut a: Array<i32> = Array::from_parts(vec![], vec![]);
+        assert_eq!(0, a.lanes_mut(3).unwrap().count());
+
+        let mut a = matrix();
+        let err = ArrayError::InvalidAxis {
+            axis: 2,
+            dimensions: 2,
+        };
+        assert_eq!(err, a.lanes(2).err().unwrap());
+        assert_eq!(err, a.lanes_mut(2).err().unwrap());
+    }
+
+    #[test]
+    fn test_views_borrow_in_place() {
+        let mut a = matrix();
+        let view = a.slice(&[1..=2, 0..=1]);
+        assert!(std::ptr::eq(&a[(1, 0)], &view[(1, 1)]));
+        assert!(std::ptr::eq(&a[(2, 1)], view.iter().next_back().unwrap()));
+        assert_eq!(vec![&9, &8, &6, &5], view.iter().rev().collect::<Vec<_>>());
+
+        let mut lanes = a
+            .lanes_mut(0)
+            .unwrap()
+            .map(|(_, lane)| lane)
+            .collect::<Vec<_>>();
+        for (i, lane) in lanes.iter_mut().enumerate() {
+            for v in lane.iter_mut().rev() {
+                *v += 10 * i as i32;
+            }
+        }
+        lanes[2][0] = 0;
+        assert_eq!(Some(&15), lanes[1].get(1));
+        drop(lanes);
+        assert_eq!("[0:2][-1:1]={{1,12,0},{4,15,26},{7,18,29}}", a.to_string());
+    }
+
+    #[test]
+    fn test_indices() {
+        let a = matrix();
+        let indices = a.indices().collect::<Vec<_>>();
+        assert_eq!(9, indices.len());
+        assert_eq!(vec![0, -1], indices[0]);
+        assert_eq!(vec![0, 1], indices[2]);
+        assert_eq!(vec![1, -1], indices[3]);
+        assert_eq!(vec![2, 1], indices[8]);
+        for (idx, v) in a.indexed_iter() {
+            assert_eq!(v, &a[&idx[..]]);
+        }
+
+        let a = Array::from_vec(vec![1i32, 2], i32::MAX - 1);
+        assert_eq!(
+            vec![vec![i32::MAX - 1], vec![i32::MAX]],
+            a.indices().collect::<Vec<_>>()
+        );
+
+        let a: Array<i32> = Array::from_parts(vec![], vec![]);
+        assert_eq!(0, a.indices().len());
+        assert_eq!(0, a.indexed_iter().count());
+    }
+
+    #[test]
+    fn test_indexed_iter_mut() {
+        let mut a = matrix();
+        for (idx, v) in a.indexed_iter_mut() {
+            *v = idx[0] * 10 + idx[1];
+        }
+        assert_eq!("[0:2][-1:1]={{-1,0,1},{9,10,11},{19,20,21}}", a.to_string());
+
+        let mut it = a.into_indexed_iter();
+        assert_eq!(9, it.len());
+        assert_eq!(Some((vec![0, -1], -1)), it.next());
+        assert_eq!(Some((vec![2, 1], 21)), it.last());
+    }
+
+    #[test]
+    fn test_map() {
+        let a = matrix().map(|v| v.to_string());
+        assert_eq!(matrix().dimensions(), a.dimensions());
+        assert_eq!("9", a[(2, 1)]);
+
+        let a = matrix().map_with_index(|idx, v| (idx.to_vec(), v));
+        assert_eq!((vec![1, 0], 5), a[(1, 0)]);
+    }
+
+    #[test]
+    fn test_try_map() {
+        let a = matrix().map(|v| v.to_string());
+        let b = a.clone().try_map(|v| v.parse::<i64>()).unwrap();
+        assert_eq!(matrix().dimensions(), b.dimensions());
+        assert_eq!(6, b[(1, 1)]);
+
+        let mut a = a;
+        a[(1, 0)] = "x".to_string();
+        a[(2, 0)] = "y".to_string();
+        let err = a.try_map(|v| v.parse::<i64>()).unwrap_err();
+        assert_eq!(vec![1, 0], err.index);
+        assert_eq!(
+            "error mapping element [1][0]: invalid digit found in string",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let a = matrix();
+        let b = matrix().map(|v| v * 10);
+        let c = a.clone().zip_with(&b, |a, b| a + b).unwrap();
+        assert_eq!(matrix().map(|v| v * 11), c);
+
+        let mut b = Array::from_vec(vec![1i32, 2, 3], 1);
+        b.wrap(0);
+        let err = a.zip_with(&b, |a, b| a + b).unwrap_err();
+        assert_eq!(
+            ArrayError::ShapeMismatch {
+                expected: matrix().dimensions().to_vec(),
+                actual: b.dimensions().to_vec(),
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_reshape() {
+        let mut a = matrix();
+        let dims = vec![
+            Dimension {
+                len: 1,
+                lower_bound: 0,
+            },
+            Dimension {
+                len: 9,
+                lower_bound: 1,
+            },
+        ];
+        a.reshape(dims.clone()).unwrap();
+        assert_eq!(&dims[..], a.dimensions());
+        assert_eq!(9, a[(0, 9)]);
+
+        let err = a.reshape(vec![Dimension {
+            len: 8,
+            lower_bound: 1,
+        }]);
+        assert_eq!(
+            Err(ArrayError::SizeMismatch {
+                dimensions: vec![Dimension {
+                    len: 8,
+                    lower_bound: 1,
+                }],
+                expected: 8,
+                actual: 9,
+            }),
+            err
+        );
+        assert_eq!(&dims[..], a.dimensions());
+        assert!(a.reshape(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_set_lower_bounds() {
+        let mut a = matrix();
+        a.set_lower_bounds(&[5, -5]).unwrap();
+        assert_eq!("[5:7][-5:-3]={{1,2,3},{4,5,6},{7,8,9}}", a.to_string());
+        assert_eq!(
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 1,
+            }),
+            a.set_lower_bounds(&[1])
+        );
+
+        a.rebase_to_one();
+        assert_eq!("{{1,2,3},{4,5,6},{7,8,9}}", a.to_string());
+    }
+
+    fn cube() -> Array<i32> {
+        let dims = vec![
+            Dimension {
+                len: 2,
+                lower_bound: 1,
+            },
+            Dimension {
+                len: 3,
+                lower_bound: 0,
+            },
+            Dimension {
+                len: 4,
+                lower_bound: -1,
+            },
+        ];
+        Array::from_parts((0..24).collect(), dims)
+    }
+
+    #[test]
+    fn test_transpose() {
+        let a = matrix().transpose();
+        assert_eq!("[-1:1][0:2]={{1,4,7},{2,5,8},{3,6,9}}", a.to_string());
+        assert_eq!(matrix(), a.transpose());
+
+        let a = cube();
+        let b = a.clone().transpose();
+        for (idx, v) in a.indexed_iter() {
+            assert_eq!(*v, b[(idx[2], idx[1], idx[0])]);
+        }
+
+        let a = Array::from_vec(vec![1i32, 2], 0);
+        assert_eq!(a.clone(), a.transpose());
+        let a: Array<i32> = Array::from_parts(vec![], vec![]);
+        assert_eq!(a.clone(), a.transpose());
+    }
+
+    #[test]
+    fn test_permute_axes() {
+        let a = cube();
+        let b = a.clone().permute_axes(&[1, 2, 0]).unwrap();
+        assert_eq!(
+            &[a.dimensions()[1], a.dimensions()[2], a.dimensions()[0]][..],
+            b.dimensions()
+        );
+        for (idx, v) in a.indexed_iter() {
+            assert_eq!(*v, b[(idx[1], idx[2], idx[0])]);
+        }
+
+        for axes in [&[0, 1][..], &[0, 1, 1], &[0, 1, 3], &[0, 1, 2, 3]] {
+            assert_eq!(
+                Err(ArrayError::InvalidPermutation {
+                    expected: 3,
+                    actual: axes.to_vec(),
+                }),
+                a.clone().permute_axes(axes)
+            );
+        }
+    }
+
+    #[test]
+    fn test_flip() {
+        let a = matrix().flip(0).unwrap();
+        assert_eq!("[0:2][-1:1]={{7,8,9},{4,5,6},{1,2,3}}", a.to_string());
+        let a = matrix().flip(1).unwrap();
+        assert_eq!("[0:2][-1:1]={{3,2,1},{6,5,4},{9,8,7}}", a.to_string());
+
+        let a = cube();
+        let b = a.clone().flip(1).unwrap();
+        for (idx, v) in a.indexed_iter() {
+            assert_eq!(*v, b[(idx[0], 2 - idx[1], idx[2])]);
+        }
+
+        assert_eq!(
+            Err(ArrayError::InvalidAxis {
+                axis: 2,
+                dimensions: 2,
+            }),
+            matrix().flip(2)
+        );
+    }
+
+    #[test]
+    fn test_concat_first_axis() {
+        // ARRAY[1,2] || ARRAY[3]
+        let a = Array::concat(
+            0,
+            vec![
+                Array::from_vec(vec![1i32, 2], 0),
+                Array::from_vec(vec![3], 5),
+            ],
+        )
+        .unwrap();
+        assert_eq!("[0:2]={1,2,3}", a.to_string());
+
+        // '[0:2][-1:1]=...' || '{{10,11,12}}' fails because of the lower bound
+        let mut b = Array::from_vec(vec![10, 11, 12], 1);
+        b.wrap(1);
+        assert_eq!(
+            Err(ArrayError::ShapeMismatch {
+                expected: vec![
+                    Dimension {
+                        len: 1,
+                        lower_bound: 1,
+                    },
+                    Dimension {
+                        len: 3,
+                        lower_bound: -1,
+                    },
+                ],
+                actual: b.dimensions().to_vec(),
+            }),
+            Array::concat(0, vec![matrix(), b.clone()])
+        );
+
+        b.set_lower_bounds(&[7, -1]).unwrap();
+        let a = Array::concat(0, vec![matrix(), b]).unwrap();
+        assert_eq!(
+            "[0:3][-1:1]={{1,2,3},{4,5,6},{7,8,9},{10,11,12}}",
+            a.to_string()
+        );
+    }
+
+    #[test]
+    fn test_concat_mixed_dimensions() {
+        // ARRAY[0,0,0] || matrix keeps the lower bound of matrix
+        let a = Array::concat(0, vec![Array::from_vec(vec![0i32; 3], -1), matrix()]).unwrap();
+        assert_eq!(
+            "[0:3][-1:1]={{0,0,0},{1,2,3},{4,5,6},{7,8,9}}",
+            a.to_string()
+        );
+
+        let a = Array::concat(0, vec![matrix(), Array::from_vec(vec![0; 3], -1)]).unwrap();
+        assert_eq!(
+            "[0:3][-1:1]={{1,2,3},{4,5,6},{7,8,9},{0,0,0}}",
+            a.to_string()
+        );
+
+        assert!(Array::concat(0, vec![matrix(), Array::from_vec(vec![0; 3], 1)]).is_err());
+        assert!(Array::concat(0, vec![Array::from_vec(vec![0; 2], -1), matrix()]).is_err());
+        assert!(Array::concat(0, vec![cube(), Array::from_vec(vec![0; 4], -1)]).is_err());
+    }
+
+    #[test]
+    fn test_concat_empty() {
+        let empty = || Array::from_parts(vec![], vec![]);
+        assert_eq!(empty(), Array::<i32>::concat(0, vec![]).unwrap());
+        assert_eq!(
+            empty(),
+            Array::<i32>::concat(3, vec![empty(), empty()]).unwrap()
+        );
+        assert_eq!(
+            matrix(),
+            Array::concat(0, vec![empty(), matrix(), empty()]).unwrap()
+        );
+        assert_eq!(
+            matrix(),
+            Array::concat(1, vec![empty(), matrix(), empty()]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_concat_inner_axis() {
+        let mut b = Array::from_vec(vec![10i32, 11, 12], 0);
+        b.wrap(7);
+        let b = b.transpose();
+        let a = Array::concat(1, vec![matrix(), b.clone(), matrix()]).unwrap();
+        assert_eq!(
+            "[0:2][-1:5]={{1,2,3,10,1,2,3},{4,5,6,11,4,5,6},{7,8,9,12,7,8,9}}",
+            a.to_string()
+        );
+
+        let c = Array::concat(2, vec![cube(), cube()]).unwrap();
+        for (idx, v) in cube().indexed_iter() {
+            assert_eq!(*v, c[(idx[0], idx[1], idx[2])]);
+            assert_eq!(*v, c[(idx[0], idx[1], idx[2] + 4)]);
+        }
+
+        assert_eq!(
+            Err(ArrayError::InvalidAxis {
+                axis: 2,
+                dimensions: 2,
+            }),
+            Array::concat(2, vec![matrix(), matrix()])
+        );
+        assert!(Array::concat(1, vec![matrix(), b.transpose()]).is_err());
+        assert!(Array::concat(1, vec![matrix(), cube()]).is_err());
+    }
+
     #[test]
     fn test_display() {
         let a = Array::from_vec(vec![0i32, 1, 2, 3, 4], 1);
@@ -149,5 +941,8 @@ mod tests {
 
         let a: Array<String> = Array::from_parts(vec![], vec![]);
         assert_eq!("{}", &format!("{}", a));
+
+        let a = Array::from_vec(vec!["a b", "", "c"], 1);
+        assert_eq!(r#"{"a b","",c}"#, &format!("{}", a));
     }
 }
@@ -0,0 +1,70 @@
+use std::error::Error;
+use std::fmt::Write;
+
+use chrono_04::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use postgres_types::IsNull;
+
+use super::element::trim;
+use super::PgTextElement;
+
+const DATE: &str = "%Y-%m-%d";
+const TIME: &str = "%H:%M:%S%.f";
+const TIMESTAMP: &str = "%Y-%m-%d %H:%M:%S%.f";
+const TIMESTAMPTZ: &str = "%Y-%m-%d %H:%M:%S%.f%#z";
+
+impl PgTextElement for NaiveDate {
+    fn from_text(s: &str) -> Result<NaiveDate, Box<dyn Error + Sync + Send>> {
+        NaiveDate::parse_from_str(trim(s), DATE).map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}", self.format(DATE)).unwrap();
+        IsNull::No
+    }
+}
+
+impl PgTextElement for NaiveTime {
+    fn from_text(s: &str) -> Result<NaiveTime, Box<dyn Error + Sync + Send>> {
+        NaiveTime::parse_from_str(trim(s), TIME).map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}", self.format(TIME)).unwrap();
+        IsNull::No
+    }
+}
+
+impl PgTextElement for NaiveDateTime {
+    fn from_text(s: &str) -> Result<NaiveDateTime, Box<dyn Error + Sync + Send>> {
+        NaiveDateTime::parse_from_str(trim(s), TIMESTAMP).map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}", self.format(TIMESTAMP)).unwrap();
+        IsNull::No
+    }
+}
+
+/// The `timestamptz` type. Values are accepted in any time zone offset.
+impl PgTextElement for DateTime<FixedOffset> {
+    fn from_text(s: &str) -> Result<DateTime<FixedOffset>, Box<dyn Error + Sync + Send>> {
+        DateTime::parse_from_str(trim(s), TIMESTAMPTZ).map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}", self.format("%Y-%m-%d %H:%M:%S%.f%:z")).unwrap();
+        IsNull::No
+    }
+}
+
+/// The `timestamptz` type. Values are accepted in any time zone offset.
+impl PgTextElement for DateTime<Utc> {
+    fn from_text(s: &str) -> Result<DateTime<Utc>, Box<dyn Error + Sync + Send>> {
+        DateTime::<FixedOffset>::from_text(s).map(|v| v.with_timezone(&Utc))
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}+00", self.format(TIMESTAMP)).unwrap();
+        IsNull::No
+    }
+}
@@ -0,0 +1,20 @@
+use std::error::Error;
+use std::fmt::Write;
+
+use postgres_types::IsNull;
+use rust_decimal_1::Decimal;
+
+use super::element::trim;
+use super::PgTextElement;
+
+/// The `numeric` type. The special values `NaN` and `Infinity` are rejected.
+impl PgTextElement for Decimal {
+    fn from_text(s: &str) -> Result<Decimal, Box<dyn Error + Sync + Send>> {
+        trim(s).parse().map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}", self).unwrap();
+        IsNull::No
+    }
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use postgres_types::IsNull;
+use serde_json_1::Value;
+
+use super::PgTextElement;
+
+/// The `json` and `jsonb` types.
+impl PgTextElement for Value {
+    fn from_text(s: &str) -> Result<Value, Box<dyn Error + Sync + Send>> {
+        serde_json_1::from_str(s).map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        out.push_str(&self.to_string());
+        IsNull::No
+    }
+}
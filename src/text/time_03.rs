@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use postgres_types::IsNull;
+use time_03::format_description::{self, OwnedFormatItem};
+use time_03::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+use super::element::trim;
+use super::PgTextElement;
+
+const DATE: &str = "[year]-[month]-[day]";
+const TIME: &str = "[hour]:[minute]:[second][optional [.[subsecond]]]";
+const TIMESTAMP: &str = "[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]";
+const TIMESTAMPTZ: &str = "[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]\
+                           [offset_hour sign:mandatory][optional [:[offset_minute]]]\
+                           [optional [:[offset_second]]]";
+
+fn description(s: &str) -> OwnedFormatItem {
+    format_description::parse_owned::<2>(s).unwrap()
+}
+
+macro_rules! time_impl {
+    ($t:ty, $parse:path, $format:expr) => {
+        impl PgTextElement for $t {
+            fn from_text(s: &str) -> Result<$t, Box<dyn Error + Sync + Send>> {
+                $parse(trim(s), &description($format)).map_err(Into::into)
+            }
+
+            fn to_text(&self, out: &mut String) -> IsNull {
+                out.push_str(&self.format(&description($format)).unwrap());
+                IsNull::No
+            }
+        }
+    };
+}
+
+time_impl!(Date, Date::parse, DATE);
+time_impl!(Time, Time::parse, TIME);
+time_impl!(PrimitiveDateTime, PrimitiveDateTime::parse, TIMESTAMP);
+// The `timestamptz` type. Values are accepted in any time zone offset.
+time_impl!(OffsetDateTime, OffsetDateTime::parse, TIMESTAMPTZ);
@@ -0,0 +1,19 @@
+use std::error::Error;
+use std::fmt::Write;
+
+use postgres_types::IsNull;
+use uuid_1::Uuid;
+
+use super::element::trim;
+use super::PgTextElement;
+
+impl PgTextElement for Uuid {
+    fn from_text(s: &str) -> Result<Uuid, Box<dyn Error + Sync + Send>> {
+        Uuid::parse_str(trim(s)).map_err(Into::into)
+    }
+
+    fn to_text(&self, out: &mut String) -> IsNull {
+        write!(out, "{}", self.hyphenated()).unwrap();
+        IsNull::No
+    }
+}
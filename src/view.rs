@@ -0,0 +1,683 @@
+//! Borrowed views into an `Array`.
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Range};
+use std::slice;
+
+use crate::array::{self, ArrayIndex, MAX_DIMENSIONS};
+use crate::text;
+use crate::{Array, Dimension};
+
+/// The offsets of the elements of a view from its first element, in the
+/// higher-dimensional equivalent of row-major order.
+///
+/// A view's elements are spaced `strides[i]` elements apart along its `i`th
+/// dimension. Strides are positive and the view never has more dimensions
+/// than the array it borrows from, so the offsets are distinct and
+/// increasing.
+#[derive(Debug, Clone)]
+struct Offsets {
+    lens: [usize; MAX_DIMENSIONS],
+    strides: [usize; MAX_DIMENSIONS],
+    ndim: usize,
+    remaining: Range<usize>,
+}
+
+impl Offsets {
+    fn new(dims: &[Dimension], strides: &[usize]) -> Offsets {
+        let mut offsets = Offsets {
+            lens: [0; MAX_DIMENSIONS],
+            strides: [0; MAX_DIMENSIONS],
+            ndim: dims.len(),
+            remaining: 0..0,
+        };
+        for (i, (dim, &stride)) in dims.iter().zip(strides).enumerate() {
+            offsets.lens[i] = dim.len as usize;
+            offsets.strides[i] = stride;
+        }
+        if !dims.is_empty() {
+            offsets.remaining.end = offsets.lens[..dims.len()].iter().product();
+        }
+        offsets
+    }
+
+    /// Returns the offset of the `i`th element.
+    fn offset(&self, mut i: usize) -> usize {
+        let mut offset = 0;
+        for axis in (0..self.ndim).rev() {
+            offset += i % self.lens[axis] * self.strides[axis];
+            i /= self.lens[axis];
+        }
+        offset
+    }
+
+    /// Returns the number of elements spanned by the view.
+    fn span(&self) -> usize {
+        match self.remaining.end {
+            0 => 0,
+            len => self.offset(len - 1) + 1,
+        }
+    }
+}
+
+impl Iterator for Offsets {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.remaining.next().map(|i| self.offset(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Offsets {
+    fn next_back(&mut self) -> Option<usize> {
+        self.remaining.next_back().map(|i| self.offset(i))
+    }
+}
+
+impl ExactSizeIterator for Offsets {}
+
+/// Returns an `Array` of unit values with the specified dimensions, used to
+/// resolve `ArrayIndex` subscripts into positions in a view.
+fn shape(dims: Vec<Dimension>, len: usize) -> Array<()> {
+    Array::from_parts(vec![(); len], dims)
+}
+
+/// A borrowed view of a subarray of an `Array`.
+///
+/// Views are created by `Array::view` and `Array::slice`. The view borrows
+/// the elements of the array in place, along with the distance between
+/// consecutive elements of each of its dimensions, so creating one does not
+/// copy or collect the elements.
+pub struct ArrayView<'a, T> {
+    data: &'a [T],
+    shape: Array<()>,
+    offsets: Offsets,
+}
+
+impl<'a, T> ArrayView<'a, T> {
+    /// Creates a view of the elements of `data` starting at its first
+    /// element, with the specified dimensions and strides.
+    pub(crate) fn new(data: &'a [T], dims: Vec<Dimension>, strides: &[usize]) -> ArrayView<'a, T> {
+        let offsets = Offsets::new(&dims, strides);
+        assert!(offsets.span() <= data.len());
+        ArrayView {
+            data,
+            shape: shape(dims, offsets.len()),
+            offsets,
+        }
+    }
+
+    /// Returns the dimensions of this view.
+    pub fn dimensions(&self) -> &[Dimension] {
+        self.shape.dimensions()
+    }
+
+    /// Returns a reference to the element at the specified index, or `None`
+    /// if the index is out of bounds.
+    pub fn get<I: ArrayIndex>(&self, idx: I) -> Option<&'a T> {
+        let idx = idx.try_index(&self.shape).ok()?;
+        Some(&self.data[self.offsets.offset(idx as usize)])
+    }
+
+    /// Returns an iterator over references to the elements of the view in
+    /// the higher-dimensional equivalent of row-major order.
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            ptr: self.data.as_ptr(),
+            offsets: self.offsets.clone(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the elements of the view into a new `Array`.
+    pub fn to_owned(&self) -> Array<T>
+    where
+        T: Clone,
+    {
+        Array::from_parts(self.iter().cloned().collect(), self.dimensions().to_vec())
+    }
+}
+
+impl<T> Clone for ArrayView<'_, T> {
+    fn clone(&self) -> Self {
+        ArrayView {
+            data: self.data,
+            shape: self.shape.clone(),
+            offsets: self.offsets.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArrayView<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ArrayView")
+            .field("dims", &self.dimensions())
+            .field("data", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArrayView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dimensions() == other.dimensions() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArrayView<'_, T> {}
+
+impl<T: fmt::Display> fmt::Display for ArrayView<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        text::write_elements(fmt, self.dimensions(), self.iter(), ',')
+    }
+}
+
+/// Indexes into the view, using subscripts relative to the view's own
+/// dimensions.
+///
+/// # Panics
+///
+/// Panics if the index does not correspond to an in-bounds element of the
+/// view.
+impl<T, I: ArrayIndex> Index<I> for ArrayView<'_, T> {
+    type Output = T;
+
+    fn index(&self, idx: I) -> &T {
+        let idx = idx.index(&self.shape);
+        &self.data[self.offsets.offset(idx as usize)]
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for ArrayView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &ArrayView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// A mutable borrowed view of a subarray of an `Array`.
+///
+/// Views are created by `Array::view_mut` and `Array::slice_mut`. Like
+/// `ArrayView`, the view borrows the elements of the array in place rather
+/// than collecting references to them.
+pub struct ArrayViewMut<'a, T> {
+    // The view's elements are borrowed through a raw pointer because the
+    // lanes produced by `LanesMut` interleave in memory, so the span of
+    // elements of one lane overlaps those of others and can't be borrowed
+    // as a `&mut [T]`. Only the elements at `offsets` are ever accessed.
+    ptr: *mut T,
+    shape: Array<()>,
+    offsets: Offsets,
+    marker: PhantomData<&'a mut T>,
+}
+
+// An `ArrayViewMut` is a set of `&'a mut T`.
+unsafe impl<T: Send> Send for ArrayViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for ArrayViewMut<'_, T> {}
+
+impl<'a, T> ArrayViewMut<'a, T> {
+    /// Creates a view of the elements of `data` starting at its first
+    /// element, with the specified dimensions and strides.
+    pub(crate) fn new(
+        data: &'a mut [T],
+        dims: Vec<Dimension>,
+        strides: &[usize],
+    ) -> ArrayViewMut<'a, T> {
+        let offsets = Offsets::new(&dims, strides);
+        assert!(offsets.span() <= data.len());
+        // Safety: the view's elements are in bounds of `data`, which is
+        // borrowed mutably for `'a`.
+        unsafe { ArrayViewMut::from_raw(data.as_mut_ptr(), dims, offsets) }
+    }
+
+    /// Creates a view of the elements at `offsets` from `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// The elements must be valid, and not accessed other than through the
+    /// view, for `'a`.
+    unsafe fn from_raw(ptr: *mut T, dims: Vec<Dimension>, offsets: Offsets) -> ArrayViewMut<'a, T> {
+        ArrayViewMut {
+            ptr,
+            shape: shape(dims, offsets.len()),
+            offsets,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the dimensions of this view.
+    pub fn dimensions(&self) -> &[Dimension] {
+        self.shape.dimensions()
+    }
+
+    /// Returns a reference to the element at the specified index, or `None`
+    /// if the index is out of bounds.
+    pub fn get<I: ArrayIndex>(&self, idx: I) -> Option<&T> {
+        let idx = idx.try_index(&self.shape).ok()?;
+        // Safety: the index is in bounds of the view.
+        unsafe { Some(&*self.ptr.add(self.offsets.offset(idx as usize))) }
+    }
+
+    /// Returns a mutable reference to the element at the specified index, or
+    /// `None` if the index is out of bounds.
+    pub fn get_mut<I: ArrayIndex>(&mut self, idx: I) -> Option<&mut T> {
+        let idx = idx.try_index(&self.shape).ok()?;
+        // Safety: the index is in bounds of the view.
+        unsafe { Some(&mut *self.ptr.add(self.offsets.offset(idx as usize))) }
+    }
+
+    /// Returns an iterator over references to the elements of the view in
+    /// the higher-dimensional equivalent of row-major order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ptr: self.ptr,
+            offsets: self.offsets.clone(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// view in the higher-dimensional equivalent of row-major order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            ptr: self.ptr,
+            offsets: self.offsets.clone(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the elements of the view into a new `Array`.
+    pub fn to_owned(&self) -> Array<T>
+    where
+        T: Clone,
+    {
+        Array::from_parts(self.iter().cloned().collect(), self.dimensions().to_vec())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArrayViewMut<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ArrayViewMut")
+            .field("dims", &self.dimensions())
+            .field("data", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArrayViewMut<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dimensions() == other.dimensions() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArrayViewMut<'_, T> {}
+
+impl<T: fmt::Display> fmt::Display for ArrayViewMut<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        text::write_elements(fmt, self.dimensions(), self.iter(), ',')
+    }
+}
+
+/// Indexes into the view, using subscripts relative to the view's own
+/// dimensions.
+///
+/// # Panics
+///
+/// Panics if the index does not correspond to an in-bounds element of the
+/// view.
+impl<T, I: ArrayIndex> Index<I> for ArrayViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, idx: I) -> &T {
+        let idx = idx.index(&self.shape);
+        // Safety: `ArrayIndex::index` panics if the index is out of bounds.
+        unsafe { &*self.ptr.add(self.offsets.offset(idx as usize)) }
+    }
+}
+
+impl<T, I: ArrayIndex> IndexMut<I> for ArrayViewMut<'_, T> {
+    fn index_mut(&mut self, idx: I) -> &mut T {
+        let idx = idx.index(&self.shape);
+        // Safety: `ArrayIndex::index` panics if the index is out of bounds.
+        unsafe { &mut *self.ptr.add(self.offsets.offset(idx as usize)) }
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for ArrayViewMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        IterMut {
+            ptr: self.ptr,
+            offsets: self.offsets,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// An iterator over references to values of a view in the
+/// higher-dimensional equivalent of row-major order.
+pub struct Iter<'a, T> {
+    ptr: *const T,
+    offsets: Offsets,
+    marker: PhantomData<&'a T>,
+}
+
+// An `Iter` is a set of `&'a T`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            ptr: self.ptr,
+            offsets: self.offsets.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        // Safety: the offsets of a view are in bounds of its elements.
+        self.offsets.next().map(|o| unsafe { &*self.ptr.add(o) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        // Safety: the offsets of a view are in bounds of its elements.
+        self.offsets
+            .next_back()
+            .map(|o| unsafe { &*self.ptr.add(o) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
+
+/// An iterator over mutable references to values of a view in the
+/// higher-dimensional equivalent of row-major order.
+pub struct IterMut<'a, T> {
+    ptr: *mut T,
+    offsets: Offsets,
+    marker: PhantomData<&'a mut T>,
+}
+
+// An `IterMut` is a set of `&'a mut T`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        // Safety: the offsets of a view are in bounds of its elements, and
+        // distinct, so each element is borrowed at most once.
+        self.offsets
+            .next()
+            .map(|o| unsafe { &mut *self.ptr.add(o) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        // Safety: as in `next`.
+        self.offsets
+            .next_back()
+            .map(|o| unsafe { &mut *self.ptr.add(o) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
+
+/// An iterator over the subarrays along the first dimension of an `Array`.
+///
+/// Created by `Array::outer_iter`.
+pub struct OuterIter<'a, T> {
+    chunks: slice::Chunks<'a, T>,
+    dims: &'a [Dimension],
+    strides: Vec<usize>,
+    index: i32,
+}
+
+impl<'a, T> OuterIter<'a, T> {
+    pub(crate) fn new(data: &'a [T], dims: &'a [Dimension]) -> OuterIter<'a, T> {
+        let (first, rest) = split_outer(dims);
+        OuterIter {
+            chunks: data.chunks(chunk_len(rest)),
+            dims: rest,
+            strides: array::strides(rest),
+            index: first,
+        }
+    }
+}
+
+impl<'a, T> Iterator for OuterIter<'a, T> {
+    type Item = (i32, ArrayView<'a, T>);
+
+    fn next(&mut self) -> Option<(i32, ArrayView<'a, T>)> {
+        let chunk = self.chunks.next()?;
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+        let view = ArrayView::new(chunk, self.dims.to_vec(), &self.strides);
+        Some((index, view))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for OuterIter<'_, T> {}
+
+/// An iterator over the mutable subarrays along the first dimension of an
+/// `Array`.
+///
+/// Created by `Array::outer_iter_mut`.
+pub struct OuterIterMut<'a, T> {
+    chunks: slice::ChunksMut<'a, T>,
+    dims: &'a [Dimension],
+    strides: Vec<usize>,
+    index: i32,
+}
+
+impl<'a, T> OuterIterMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], dims: &'a [Dimension]) -> OuterIterMut<'a, T> {
+        let (first, rest) = split_outer(dims);
+        OuterIterMut {
+            chunks: data.chunks_mut(chunk_len(rest)),
+            dims: rest,
+            strides: array::strides(rest),
+            index: first,
+        }
+    }
+}
+
+impl<'a, T> Iterator for OuterIterMut<'a, T> {
+    type Item = (i32, ArrayViewMut<'a, T>);
+
+    fn next(&mut self) -> Option<(i32, ArrayViewMut<'a, T>)> {
+        let chunk = self.chunks.next()?;
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+        let view = ArrayViewMut::new(chunk, self.dims.to_vec(), &self.strides);
+        Some((index, view))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for OuterIterMut<'_, T> {}
+
+fn split_outer(dims: &[Dimension]) -> (i32, &[Dimension]) {
+    match dims.split_first() {
+        Some((first, rest)) => (first.lower_bound, rest),
+        None => (0, dims),
+    }
+}
+
+fn chunk_len(dims: &[Dimension]) -> usize {
+    dims.iter()
+        .map(|d| d.len as usize)
+        .product::<usize>()
+        .max(1)
+}
+
+/// An iterator over the one-dimensional lanes of an `Array` along an axis.
+///
+/// Created by `Array::lanes` and `Array::rows`.
+pub struct Lanes<'a, T> {
+    data: &'a [T],
+    dims: &'a [Dimension],
+    axis: usize,
+    stride: usize,
+    lanes: Range<usize>,
+}
+
+impl<'a, T> Lanes<'a, T> {
+    pub(crate) fn new(data: &'a [T], dims: &'a [Dimension], axis: usize) -> Lanes<'a, T> {
+        let count = lane_count(data.len(), dims, axis);
+        Lanes {
+            data,
+            dims,
+            axis,
+            stride: dims.get(axis + 1..).map_or(1, chunk_len),
+            lanes: 0..count,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Lanes<'a, T> {
+    type Item = (Vec<i32>, ArrayView<'a, T>);
+
+    fn next(&mut self) -> Option<(Vec<i32>, ArrayView<'a, T>)> {
+        let lane = self.lanes.next()?;
+        let dim = self.dims[self.axis];
+        let start = lane_start(lane, dim, self.stride);
+        let view = ArrayView::new(&self.data[start..], vec![dim], &[self.stride]);
+        Some((lane_subscripts(self.dims, self.axis, lane), view))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lanes.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Lanes<'_, T> {}
+
+/// An iterator over the mutable one-dimensional lanes of an `Array` along an
+/// axis.
+///
+/// Created by `Array::lanes_mut` and `Array::rows_mut`.
+pub struct LanesMut<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    dims: &'a [Dimension],
+    axis: usize,
+    stride: usize,
+    lanes: Range<usize>,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+// A `LanesMut` is a `&'a mut [T]`.
+unsafe impl<T: Send> Send for LanesMut<'_, T> {}
+unsafe impl<T: Sync> Sync for LanesMut<'_, T> {}
+
+impl<'a, T> LanesMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], dims: &'a [Dimension], axis: usize) -> LanesMut<'a, T> {
+        let count = lane_count(data.len(), dims, axis);
+        LanesMut {
+            ptr: data.as_mut_ptr(),
+            len: data.len(),
+            dims,
+            axis,
+            stride: dims.get(axis + 1..).map_or(1, chunk_len),
+            lanes: 0..count,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LanesMut<'a, T> {
+    type Item = (Vec<i32>, ArrayViewMut<'a, T>);
+
+    fn next(&mut self) -> Option<(Vec<i32>, ArrayViewMut<'a, T>)> {
+        let lane = self.lanes.next()?;
+        let dim = self.dims[self.axis];
+        let start = lane_start(lane, dim, self.stride);
+        let offsets = Offsets::new(&[dim], &[self.stride]);
+        assert!(start + offsets.span() <= self.len);
+        // Safety: the elements are in bounds of the array, which is borrowed
+        // mutably for `'a`, and each element belongs to exactly one lane.
+        let view = unsafe { ArrayViewMut::from_raw(self.ptr.add(start), vec![dim], offsets) };
+        Some((lane_subscripts(self.dims, self.axis, lane), view))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lanes.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for LanesMut<'_, T> {}
+
+/// Returns the number of lanes along `axis` of an array of `len` elements,
+/// which must be empty if `axis` is out of range.
+fn lane_count(len: usize, dims: &[Dimension], axis: usize) -> usize {
+    match dims.get(axis) {
+        Some(dim) if len != 0 => len / dim.len as usize,
+        _ => 0,
+    }
+}
+
+/// Returns the offset of the first element of the specified lane, for lanes
+/// along a dimension whose consecutive elements are `stride` apart.
+fn lane_start(lane: usize, dim: Dimension, stride: usize) -> usize {
+    lane / stride * stride * dim.len as usize + lane % stride
+}
+
+/// Returns the subscripts along every axis but `axis` of the specified lane.
+fn lane_subscripts(dims: &[Dimension], axis: usize, mut lane: usize) -> Vec<i32> {
+    let mut subscripts = vec![0; dims.len() - 1];
+    for (i, dim) in dims.iter().enumerate().rev().filter(|&(i, _)| i != axis) {
+        let len = dim.len as usize;
+        let subscript = dim.lower_bound + (lane % len) as i32;
+        lane /= len;
+        subscripts[if i > axis { i - 1 } else { i }] = subscript;
+    }
+    subscripts
+}